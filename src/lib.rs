@@ -111,6 +111,50 @@
 //! - `pub fn try_to_query_string(&self) -> Result<String, serde_qs::Error>`: Converts the struct into
 //!   a query string. Returns a `Result` with either the query string or an error.
 //!
+//! ## Round-Trip Deserialization
+//!
+//! The companion derive macro `FromQueryString` provides the inverse operation: parsing a
+//! query string back into a struct. This is useful when building typed query extractors
+//! (as frameworks like actix, poem, or cataclysm do around `serde_qs::from_str`).
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use url_query_string::FromQueryString;
+//!
+//! #[derive(Deserialize, FromQueryString)]
+//! #[serde(rename_all = "camelCase")]
+//! struct SearchParams {
+//!     pub page: Option<u32>,
+//!     pub page_size: Option<u32>,
+//! }
+//!
+//! let parsed = SearchParams::from_query_string("page=1&pageSize=20").unwrap();
+//! assert_eq!(parsed.page, Some(1));
+//! ```
+//!
+//! ## Configuring `serde_qs` Behavior
+//!
+//! `serde_qs` exposes a [`Config`](https://docs.rs/serde_qs/latest/serde_qs/struct.Config.html)
+//! for tuning the maximum nesting depth and whether bracket parsing is strict, but `Config`
+//! only offers `deserialize_str`/`deserialize_bytes` — there is no way to serialize through
+//! it. A container-level `#[query_string(max_depth = ..., strict = ...)]` attribute therefore
+//! only affects `FromQueryString::from_query_string`; `ToQueryString` always serializes
+//! through the `serde_qs` top-level helpers.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use url_query_string::FromQueryString;
+//!
+//! #[derive(Deserialize, FromQueryString, Debug, PartialEq)]
+//! #[query_string(max_depth = 2, strict = false)]
+//! struct DeepStruct {
+//!     pub tags: Vec<String>,
+//! }
+//!
+//! let parsed = DeepStruct::from_query_string("tags[0]=a&tags[1]=b").unwrap();
+//! assert_eq!(parsed.tags, vec!["a".to_string(), "b".to_string()]);
+//! ```
+//!
 //! ## Contribution
 //!
 //! Contributions are welcome! If you encounter any bugs or have feature requests, please
@@ -118,8 +162,234 @@
 
 extern crate proc_macro;
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitBool, LitInt, LitStr};
+
+/// The `max_depth` `serde_qs` falls back to when a `#[query_string(...)]` attribute
+/// is present but does not specify one.
+const DEFAULT_MAX_DEPTH: usize = 5;
+
+/// The `strict` mode `serde_qs` falls back to when a `#[query_string(...)]` attribute
+/// is present but does not specify one.
+const DEFAULT_STRICT: bool = true;
+
+/// How a sequence (`Vec`-like) field should be encoded in the generated query string.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArrayStyle {
+    /// `a[0]=1&a[1]=3` — the default `serde_qs` behavior.
+    Indexed,
+    /// `a=1&a=3` — the same key repeated once per element.
+    Repeated,
+    /// `a=1,3` — every element joined into a single comma-separated value.
+    Comma,
+}
+
+/// How a `bool` field should be coerced when serialized, for APIs that expect `1`/`0` or
+/// presence/absence rather than `true`/`false`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BoolStyle {
+    /// Always emit `1` or `0` in place of `true`/`false`.
+    Int,
+    /// Emit `1` when `true`; omit the field entirely when `false`.
+    Flag,
+}
+
+/// The `#[query_string(...)]` knobs, which can appear on a struct (as the `serde_qs::Config`
+/// used for deserialization and the default array style for every sequence field) or on an
+/// individual field (overriding the container's default array style, or coercing a `bool`
+/// field).
+#[derive(Default)]
+struct QueryStringAttr {
+    max_depth: Option<usize>,
+    strict: Option<bool>,
+    array: Option<ArrayStyle>,
+    bool_style: Option<BoolStyle>,
+}
+
+/// Parses a `#[query_string(max_depth = 5, strict = false, array = "comma", bool = "int")]`
+/// attribute, whether it appears on the struct or on one of its fields.
+fn parse_query_string_attr(attrs: &[Attribute]) -> QueryStringAttr {
+    let mut parsed = QueryStringAttr::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("query_string") {
+            continue;
+        }
+
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("max_depth") {
+                let value = meta.value()?;
+                let lit: LitInt = value.parse()?;
+                parsed.max_depth = Some(lit.base10_parse()?);
+            } else if meta.path.is_ident("strict") {
+                let value = meta.value()?;
+                let lit: LitBool = value.parse()?;
+                parsed.strict = Some(lit.value());
+            } else if meta.path.is_ident("array") {
+                let value = meta.value()?;
+                let lit: LitStr = value.parse()?;
+                parsed.array = Some(match lit.value().as_str() {
+                    "repeated" => ArrayStyle::Repeated,
+                    "comma" => ArrayStyle::Comma,
+                    _ => ArrayStyle::Indexed,
+                });
+            } else if meta.path.is_ident("bool") {
+                let value = meta.value()?;
+                let lit: LitStr = value.parse()?;
+                parsed.bool_style = Some(match lit.value().as_str() {
+                    "flag" => BoolStyle::Flag,
+                    _ => BoolStyle::Int,
+                });
+            }
+            Ok(())
+        });
+    }
+
+    parsed
+}
+
+/// Builds the `serde_qs::Config` expression to deserialize through, if the struct carries
+/// a `#[query_string(...)]` container attribute. Returns `None` when no such attribute sets
+/// `max_depth` or `strict`, so callers can fall back to the `serde_qs` top-level helpers. `serde_qs::Config`
+/// has no serialization entry point, so this is only ever used by `FromQueryString`.
+fn container_config_tokens(config: &QueryStringAttr) -> Option<TokenStream2> {
+    if config.max_depth.is_none() && config.strict.is_none() {
+        return None;
+    }
+
+    let max_depth = config.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+    let strict = config.strict.unwrap_or(DEFAULT_STRICT);
+
+    Some(quote! { serde_qs::Config::new(#max_depth, #strict) })
+}
+
+/// Returns `true` when `ty` is (syntactically) a `Vec<_>`.
+fn is_vec_type(ty: &syn::Type) -> bool {
+    is_path_type_named(ty, "Vec")
+}
+
+/// Returns `true` when `ty` is (syntactically) `bool`.
+fn is_bool_type(ty: &syn::Type) -> bool {
+    is_path_type_named(ty, "bool")
+}
+
+/// Returns `true` when `ty`'s final path segment has the given identifier.
+fn is_path_type_named(ty: &syn::Type, ident: &str) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == ident),
+        _ => false,
+    }
+}
+
+/// Reads `#[serde(rename_all = "...")]` off a struct's attributes, if present.
+fn parse_serde_rename_all(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("serde") {
+            return None;
+        }
+        let mut rename_all = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value = meta.value()?;
+                let lit: LitStr = value.parse()?;
+                rename_all = Some(lit.value());
+            }
+            Ok(())
+        });
+        rename_all
+    })
+}
+
+/// Reads `#[serde(rename = "...")]` off a field's attributes, if present.
+fn parse_serde_rename(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("serde") {
+            return None;
+        }
+        let mut rename = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let value = meta.value()?;
+                let lit: LitStr = value.parse()?;
+                rename = Some(lit.value());
+            }
+            Ok(())
+        });
+        rename
+    })
+}
+
+/// Applies a `serde(rename_all = "...")` case style to a field name. Covers the styles
+/// `serde` itself supports that are realistic for query string field names.
+fn apply_rename_all(field_name: &str, style: &str) -> String {
+    let words: Vec<&str> = field_name.split('_').filter(|w| !w.is_empty()).collect();
+
+    match style {
+        "camelCase" => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            })
+            .collect(),
+        "PascalCase" => words.iter().map(|w| capitalize(w)).collect(),
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        "kebab-case" => words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("-"),
+        "SCREAMING-KEBAB-CASE" => words
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("-"),
+        "lowercase" => field_name.to_string(),
+        "UPPERCASE" => field_name.to_uppercase(),
+        _ => field_name.to_string(),
+    }
+}
+
+/// Capitalizes the first character of `word`, lowercasing the rest.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+/// Resolves the key a field is serialized under, applying an explicit `#[serde(rename)]`
+/// if present, falling back to the container's `#[serde(rename_all)]` style, falling back
+/// to the field's own name.
+fn resolve_field_key(
+    field_name: &str,
+    container_rename_all: Option<&str>,
+    field_rename: Option<&str>,
+) -> String {
+    if let Some(rename) = field_rename {
+        return rename.to_string();
+    }
+
+    match container_rename_all {
+        Some(style) => apply_rename_all(field_name, style),
+        None => field_name.to_string(),
+    }
+}
 
 /// Procedural macro to derive query string serialization methods for structs.
 ///
@@ -157,12 +427,380 @@ use syn::{parse_macro_input, DeriveInput};
 /// // Generate query string
 /// assert_eq!(instance.to_query_string(), "userId=user_123&page=1");
 /// ```
-#[proc_macro_derive(ToQueryString)]
+///
+/// ## Array Encoding
+///
+/// `serde_qs` encodes `Vec` fields as indexed brackets (`a[0]=1&a[1]=3`) by default. A
+/// field (or, as a default for every sequence field, the struct itself) can opt into a
+/// different style with `#[query_string(array = "...")]`:
+///
+/// - `"indexed"` (the default): `a[0]=1&a[1]=3`.
+/// - `"comma"`: `a=1,3` — every element joined into one comma-separated value.
+/// - `"repeated"`: `a=1&a=3` — the same key repeated once per element.
+///
+/// ```rust
+/// use serde::Serialize;
+/// use url_query_string::ToQueryString;
+///
+/// #[derive(Serialize, ToQueryString)]
+/// struct SearchFilter {
+///     #[query_string(array = "comma")]
+///     pub state: Vec<String>,
+/// }
+///
+/// let filter = SearchFilter { state: vec!["open".to_string(), "closed".to_string()] };
+/// assert_eq!(filter.to_query_string(), "state=open,closed");
+/// ```
+///
+/// ## Boolean Coercion
+///
+/// Many web APIs expect booleans as `1`/`0`, or as presence/absence of the key, rather than
+/// `true`/`false`. A `bool` field can opt into this with `#[query_string(bool = "...")]`:
+///
+/// - `"int"`: always emit `1` or `0` in place of `true`/`false`.
+/// - `"flag"`: emit `1` when `true`; omit the field entirely when `false`.
+///
+/// ```rust
+/// use serde::Serialize;
+/// use url_query_string::ToQueryString;
+///
+/// #[derive(Serialize, ToQueryString)]
+/// struct DirectoryRequest {
+///     #[query_string(bool = "flag")]
+///     pub local: bool,
+/// }
+///
+/// assert_eq!(DirectoryRequest { local: true }.to_query_string(), "local=1");
+/// assert_eq!(DirectoryRequest { local: false }.to_query_string(), "");
+/// ```
+///
+/// ## Building a Full URL
+///
+/// Two further methods are always generated, for splicing the query string onto an
+/// endpoint:
+///
+/// - `to_query_string_prefixed`: The query string with a leading `?`, or an empty string
+///   if there is nothing to serialize.
+/// - `to_url`: Appends the query string onto `base`, using `?` or `&` as appropriate, and
+///   leaving `base` untouched when the query string is empty (no dangling `?`).
+///
+/// ```rust
+/// use serde::Serialize;
+/// use url_query_string::ToQueryString;
+///
+/// #[derive(Serialize, ToQueryString)]
+/// struct Empty {}
+///
+/// #[derive(Serialize, ToQueryString)]
+/// struct Paged {
+///     pub page: Option<u32>,
+/// }
+///
+/// assert_eq!(Empty {}.to_url("https://api.example.com/items"), "https://api.example.com/items");
+/// assert_eq!(
+///     Paged { page: Some(2) }.to_url("https://api.example.com/items"),
+///     "https://api.example.com/items?page=2"
+/// );
+/// assert_eq!(
+///     Paged { page: Some(2) }.to_url("https://api.example.com/items?sort=asc"),
+///     "https://api.example.com/items?sort=asc&page=2"
+/// );
+/// ```
+#[proc_macro_derive(ToQueryString, attributes(query_string))]
 pub fn to_query_string_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
+    let container_attr = parse_query_string_attr(&input.attrs);
+    let container_rename_all = parse_serde_rename_all(&input.attrs);
+
+    let named_fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Some(&fields.named),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let mut passthrough = Vec::new();
+    let mut comma_fields = Vec::new();
+    let mut repeated_fields = Vec::new();
+    let mut bool_fields = Vec::new();
+
+    if let Some(fields) = named_fields {
+        for field in fields {
+            let field_attr = parse_query_string_attr(&field.attrs);
+
+            if is_vec_type(&field.ty) {
+                let style = field_attr
+                    .array
+                    .or(container_attr.array)
+                    .unwrap_or(ArrayStyle::Indexed);
+
+                match style {
+                    ArrayStyle::Indexed => passthrough.push(field),
+                    ArrayStyle::Comma => comma_fields.push(field),
+                    ArrayStyle::Repeated => repeated_fields.push(field),
+                }
+            } else if is_bool_type(&field.ty) && field_attr.bool_style.is_some() {
+                bool_fields.push((field, field_attr.bool_style.unwrap()));
+            } else {
+                passthrough.push(field);
+            }
+        }
+    }
+
+    let url_helper_methods = quote! {
+        /// Converts the struct into a query string, with a leading `?` when non-empty.
+        ///
+        /// # Returns
+        ///
+        /// An empty `String` if the query string is empty, otherwise the query string
+        /// prefixed with `?`.
+        pub fn to_query_string_prefixed(&self) -> String {
+            let query_string = self.to_query_string();
+            if query_string.is_empty() {
+                String::new()
+            } else {
+                format!("?{}", query_string)
+            }
+        }
+
+        /// Appends the struct's query string onto `base`.
+        ///
+        /// # Returns
+        ///
+        /// `base` unchanged if the query string is empty. Otherwise `base` joined with the
+        /// query string using `?` if `base` has no query component yet, or `&` if it does.
+        pub fn to_url(&self, base: &str) -> String {
+            let query_string = self.to_query_string();
+            if query_string.is_empty() {
+                base.to_string()
+            } else if base.contains('?') {
+                format!("{}&{}", base, query_string)
+            } else {
+                format!("{}?{}", base, query_string)
+            }
+        }
+    };
+
+    // No field opts into a custom array/bool encoding: serialize `self` directly, exactly
+    // as before the `#[query_string(...)]` field attributes existed.
+    if comma_fields.is_empty() && repeated_fields.is_empty() && bool_fields.is_empty() {
+        // `serde_qs::Config` has no serialization entry point (`deserialize_str`/
+        // `deserialize_bytes` only), so a `#[query_string(max_depth, strict)]` container
+        // attribute cannot influence serialization; always go through the top-level helper.
+        let to_query_string_body = quote! { serde_qs::to_string(self).unwrap_or_default() };
+        let try_to_query_string_body = quote! { serde_qs::to_string(self) };
+
+        let gen = quote! {
+            impl #name {
+                /// Converts the struct into a query string, ignoring errors.
+                ///
+                /// # Returns
+                ///
+                /// A `String` containing the query string. If serialization fails,
+                /// it returns an empty string (`""`).
+                pub fn to_query_string(&self) -> String {
+                    #to_query_string_body
+                }
+
+                /// Converts the struct into a query string, returning a `Result`.
+                ///
+                /// # Returns
+                ///
+                /// A `Result` containing either:
+                /// - `Ok(String)`: The generated query string.
+                /// - `Err(serde_qs::Error)`: An error encountered during serialization.
+                pub fn try_to_query_string(&self) -> Result<String, serde_qs::Error> {
+                    #try_to_query_string_body
+                }
+
+                #url_helper_methods
+            }
+        };
+
+        return gen.into();
+    }
+
+    // At least one field opts into `comma`/`repeated` array encoding or `int`/`flag` bool
+    // coercion. `serde_qs` only ever sees the struct's own `Serialize` impl, so there is no
+    // way to retroactively attach a `serialize_with` to one of its fields. Instead, build a
+    // private shadow struct that mirrors the original fields (borrowing the indexed and other
+    // plain ones, holding the coerced `i64` value of each bool field, skipping the comma and
+    // repeated ones) and serialize that, then append the comma and repeated fields by hand
+    // afterwards.
+    let shadow_name = format_ident!("__{}QueryStringShadow", name);
+    let needs_lifetime = !passthrough.is_empty();
+    let lifetime = if needs_lifetime {
+        quote! { <'__qs> }
+    } else {
+        quote! {}
+    };
+    let lifetime_ref = if needs_lifetime {
+        quote! { '__qs }
+    } else {
+        quote! {}
+    };
+
+    let container_serde_attrs: Vec<_> = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .collect();
+
+    let passthrough_idents: Vec<_> = passthrough
+        .iter()
+        .map(|f| f.ident.clone().unwrap())
+        .collect();
+    let passthrough_tys: Vec<_> = passthrough.iter().map(|f| &f.ty).collect();
+    let passthrough_attrs: Vec<TokenStream2> = passthrough
+        .iter()
+        .map(|f| {
+            let attrs: Vec<_> = f
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("serde"))
+                .collect();
+            quote! { #(#attrs)* }
+        })
+        .collect();
+
+    let comma_idents: Vec<_> = comma_fields
+        .iter()
+        .map(|f| f.ident.clone().unwrap())
+        .collect();
+    let comma_keys: Vec<String> = comma_fields
+        .iter()
+        .map(|f| {
+            let field_rename = parse_serde_rename(&f.attrs);
+            resolve_field_key(
+                &f.ident.clone().unwrap().to_string(),
+                container_rename_all.as_deref(),
+                field_rename.as_deref(),
+            )
+        })
+        .collect();
+
+    let repeated_idents: Vec<_> = repeated_fields
+        .iter()
+        .map(|f| f.ident.clone().unwrap())
+        .collect();
+    let repeated_keys: Vec<String> = repeated_fields
+        .iter()
+        .map(|f| {
+            let field_rename = parse_serde_rename(&f.attrs);
+            resolve_field_key(
+                &f.ident.clone().unwrap().to_string(),
+                container_rename_all.as_deref(),
+                field_rename.as_deref(),
+            )
+        })
+        .collect();
+
+    let bool_decls: Vec<TokenStream2> = bool_fields
+        .iter()
+        .map(|(f, style)| {
+            let ident = f.ident.clone().unwrap();
+            let attrs: Vec<_> = f
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("serde"))
+                .collect();
+            match style {
+                BoolStyle::Int => quote! { #(#attrs)* #ident: i64, },
+                BoolStyle::Flag => quote! {
+                    #(#attrs)*
+                    #[serde(skip_serializing_if = "Option::is_none")]
+                    #ident: Option<i64>,
+                },
+            }
+        })
+        .collect();
+    let bool_inits: Vec<TokenStream2> = bool_fields
+        .iter()
+        .map(|(f, style)| {
+            let ident = f.ident.clone().unwrap();
+            match style {
+                BoolStyle::Int => quote! { #ident: if self.#ident { 1 } else { 0 }, },
+                BoolStyle::Flag => {
+                    quote! { #ident: if self.#ident { Some(1) } else { None }, }
+                }
+            }
+        })
+        .collect();
+
+    let shadow_struct = quote! {
+        #[derive(serde::Serialize)]
+        #(#container_serde_attrs)*
+        struct #shadow_name #lifetime {
+            #(
+                #passthrough_attrs
+                #passthrough_idents: &#lifetime_ref #passthrough_tys,
+            )*
+            #(#bool_decls)*
+        }
+    };
+
+    let shadow_init = quote! {
+        #shadow_name {
+            #(#passthrough_idents: &self.#passthrough_idents,)*
+            #(#bool_inits)*
+        }
+    };
+
+    // As above: `serde_qs::Config` cannot serialize, so the shadow struct always goes
+    // through `serde_qs::to_string` regardless of any container `#[query_string(...)]`.
+    let serialize_shadow = quote! { serde_qs::to_string(&__shadow) };
+
+    let append_repeated = quote! {
+        #(
+            for __value in self.#repeated_idents.iter() {
+                let mut __pair = std::collections::BTreeMap::new();
+                __pair.insert(#repeated_keys, __value);
+                let __encoded = serde_qs::to_string(&__pair)?;
+                if !__qs.is_empty() {
+                    __qs.push('&');
+                }
+                __qs.push_str(&__encoded);
+            }
+        )*
+    };
+
+    // `comma`-joined values can't be routed through `serde_qs` as a single string: it
+    // percent-encodes the separator along with everything else, turning `open,closed` into
+    // `open%2Cclosed`. Instead, percent-encode each element on its own (by serializing a
+    // one-entry map and stripping the `key=` prefix serde_qs adds) and join those encoded
+    // elements with a literal comma.
+    let append_comma = quote! {
+        #(
+            {
+                let mut __comma_values: Vec<String> = Vec::new();
+                for __value in self.#comma_idents.iter() {
+                    let mut __pair = std::collections::BTreeMap::new();
+                    __pair.insert(#comma_keys, __value);
+                    let __encoded_pair = serde_qs::to_string(&__pair)?;
+                    let __encoded_value = __encoded_pair
+                        .strip_prefix(concat!(#comma_keys, "="))
+                        .unwrap_or(__encoded_pair.as_str())
+                        .to_string();
+                    __comma_values.push(__encoded_value);
+                }
+                if !__comma_values.is_empty() {
+                    if !__qs.is_empty() {
+                        __qs.push('&');
+                    }
+                    __qs.push_str(#comma_keys);
+                    __qs.push('=');
+                    __qs.push_str(&__comma_values.join(","));
+                }
+            }
+        )*
+    };
+
     let gen = quote! {
+        #shadow_struct
+
         impl #name {
             /// Converts the struct into a query string, ignoring errors.
             ///
@@ -171,7 +809,7 @@ pub fn to_query_string_derive(input: TokenStream) -> TokenStream {
             /// A `String` containing the query string. If serialization fails,
             /// it returns an empty string (`""`).
             pub fn to_query_string(&self) -> String {
-                serde_qs::to_string(self).unwrap_or_default()
+                self.try_to_query_string().unwrap_or_default()
             }
 
             /// Converts the struct into a query string, returning a `Result`.
@@ -182,7 +820,81 @@ pub fn to_query_string_derive(input: TokenStream) -> TokenStream {
             /// - `Ok(String)`: The generated query string.
             /// - `Err(serde_qs::Error)`: An error encountered during serialization.
             pub fn try_to_query_string(&self) -> Result<String, serde_qs::Error> {
-                serde_qs::to_string(self)
+                let __shadow = #shadow_init;
+                let mut __qs = #serialize_shadow?;
+                #append_comma
+                #append_repeated
+                Ok(__qs)
+            }
+
+            #url_helper_methods
+        }
+    };
+
+    gen.into()
+}
+
+/// Procedural macro to derive query string deserialization methods for structs.
+///
+/// This macro generates two methods for the struct:
+///
+/// - `from_query_string`: Parses a query string into the struct, returning a
+///   `Result<Self, serde_qs::Error>`.
+/// - `from_query_string_lossy`: Parses a query string into the struct, returning `None`
+///   instead of an error if parsing fails.
+///
+/// ## Usage
+///
+/// The struct must implement the `serde::Deserialize` trait, as the macro relies on
+/// `serde_qs` for query string deserialization.
+///
+/// ### Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use url_query_string::FromQueryString;
+///
+/// #[derive(Deserialize, FromQueryString)]
+/// #[serde(rename_all = "camelCase")]
+/// struct ExampleStruct {
+///     pub user_id: Option<String>,
+///     pub page: Option<u32>,
+/// }
+///
+/// let instance = ExampleStruct::from_query_string("userId=user_123&page=1").unwrap();
+/// assert_eq!(instance.page, Some(1));
+/// ```
+#[proc_macro_derive(FromQueryString, attributes(query_string))]
+pub fn from_query_string_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let config = container_config_tokens(&parse_query_string_attr(&input.attrs));
+    let from_query_string_body = match &config {
+        Some(config) => quote! { #config.deserialize_str(s) },
+        None => quote! { serde_qs::from_str(s) },
+    };
+
+    let gen = quote! {
+        impl #name {
+            /// Parses a query string into the struct.
+            ///
+            /// # Returns
+            ///
+            /// A `Result` containing either:
+            /// - `Ok(Self)`: The deserialized struct.
+            /// - `Err(serde_qs::Error)`: An error encountered during deserialization.
+            pub fn from_query_string(s: &str) -> Result<Self, serde_qs::Error> {
+                #from_query_string_body
+            }
+
+            /// Parses a query string into the struct, ignoring errors.
+            ///
+            /// # Returns
+            ///
+            /// `Some(Self)` if deserialization succeeds, or `None` if it fails.
+            pub fn from_query_string_lossy(s: &str) -> Option<Self> {
+                Self::from_query_string(s).ok()
             }
         }
     };
@@ -0,0 +1,51 @@
+use serde::Serialize;
+use url_query_string::ToQueryString;
+
+#[derive(Serialize, ToQueryString)]
+struct CommaFilter {
+    #[query_string(array = "comma")]
+    pub state: Vec<String>,
+}
+
+#[derive(Serialize, ToQueryString)]
+struct RepeatedFilter {
+    #[query_string(array = "repeated")]
+    pub state: Vec<String>,
+    pub page: Option<u32>,
+}
+
+#[derive(Serialize, ToQueryString)]
+struct IndexedFilter {
+    pub state: Vec<String>,
+}
+
+#[test]
+fn test_comma_array_encoding() {
+    let filter = CommaFilter {
+        state: vec!["open".to_string(), "closed".to_string()],
+    };
+    assert_eq!(filter.to_query_string(), "state=open,closed");
+}
+
+#[test]
+fn test_comma_array_encoding_empty() {
+    let filter = CommaFilter { state: vec![] };
+    assert_eq!(filter.to_query_string(), "");
+}
+
+#[test]
+fn test_repeated_array_encoding() {
+    let filter = RepeatedFilter {
+        state: vec!["open".to_string(), "closed".to_string()],
+        page: Some(1),
+    };
+    assert_eq!(filter.to_query_string(), "page=1&state=open&state=closed");
+}
+
+#[test]
+fn test_indexed_array_encoding_is_default() {
+    let filter = IndexedFilter {
+        state: vec!["open".to_string(), "closed".to_string()],
+    };
+    assert_eq!(filter.to_query_string(), "state[0]=open&state[1]=closed");
+}
@@ -0,0 +1,45 @@
+use serde::Serialize;
+use url_query_string::ToQueryString;
+
+#[derive(Serialize, ToQueryString)]
+struct IntBool {
+    #[query_string(bool = "int")]
+    pub local: bool,
+    pub page: Option<u32>,
+}
+
+#[derive(Serialize, ToQueryString)]
+struct FlagBool {
+    #[query_string(bool = "flag")]
+    pub local: bool,
+}
+
+#[test]
+fn test_bool_int_true() {
+    let instance = IntBool {
+        local: true,
+        page: Some(1),
+    };
+    assert_eq!(instance.to_query_string(), "page=1&local=1");
+}
+
+#[test]
+fn test_bool_int_false() {
+    let instance = IntBool {
+        local: false,
+        page: Some(1),
+    };
+    assert_eq!(instance.to_query_string(), "page=1&local=0");
+}
+
+#[test]
+fn test_bool_flag_true() {
+    let instance = FlagBool { local: true };
+    assert_eq!(instance.to_query_string(), "local=1");
+}
+
+#[test]
+fn test_bool_flag_false_is_omitted() {
+    let instance = FlagBool { local: false };
+    assert_eq!(instance.to_query_string(), "");
+}
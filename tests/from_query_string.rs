@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use url_query_string::FromQueryString;
+
+#[derive(Deserialize, FromQueryString, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct TestStruct {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+    pub id: Option<String>,
+    pub user_id: Option<String>,
+}
+
+#[test]
+fn test_from_query_string() {
+    let instance = TestStruct::from_query_string("page=1&pageSize=20&id=test_id&userId=user_123")
+        .unwrap();
+
+    assert_eq!(
+        instance,
+        TestStruct {
+            page: Some(1),
+            page_size: Some(20),
+            id: Some("test_id".to_string()),
+            user_id: Some("user_123".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_from_query_string_lossy_ok() {
+    let instance = TestStruct::from_query_string_lossy("page=1&pageSize=20");
+
+    assert_eq!(
+        instance,
+        Some(TestStruct {
+            page: Some(1),
+            page_size: Some(20),
+            id: None,
+            user_id: None,
+        })
+    );
+}
+
+#[test]
+fn test_from_query_string_lossy_err() {
+    let instance = TestStruct::from_query_string_lossy("page=not_a_number");
+    assert_eq!(instance, None);
+}
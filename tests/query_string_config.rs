@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use url_query_string::{FromQueryString, ToQueryString};
+
+#[derive(Serialize, Deserialize, ToQueryString, FromQueryString, Debug, PartialEq)]
+#[query_string(max_depth = 2, strict = false)]
+struct NestedStruct {
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn test_to_query_string_with_config() {
+    let instance = NestedStruct {
+        tags: vec!["open".to_string(), "closed".to_string()],
+    };
+
+    assert_eq!(instance.to_query_string(), "tags[0]=open&tags[1]=closed");
+}
+
+#[test]
+fn test_from_query_string_with_config() {
+    let instance = NestedStruct::from_query_string("tags[0]=open&tags[1]=closed").unwrap();
+
+    assert_eq!(
+        instance,
+        NestedStruct {
+            tags: vec!["open".to_string(), "closed".to_string()],
+        }
+    );
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+use url_query_string::ToQueryString;
+
+#[derive(Serialize, ToQueryString)]
+struct Empty {}
+
+#[derive(Serialize, ToQueryString)]
+struct Paged {
+    pub page: Option<u32>,
+}
+
+#[test]
+fn test_to_query_string_prefixed_empty() {
+    assert_eq!(Empty {}.to_query_string_prefixed(), "");
+}
+
+#[test]
+fn test_to_query_string_prefixed_non_empty() {
+    let instance = Paged { page: Some(2) };
+    assert_eq!(instance.to_query_string_prefixed(), "?page=2");
+}
+
+#[test]
+fn test_to_url_empty_query_string_leaves_base_untouched() {
+    let instance = Empty {};
+    assert_eq!(
+        instance.to_url("https://api.example.com/items"),
+        "https://api.example.com/items"
+    );
+}
+
+#[test]
+fn test_to_url_without_existing_query() {
+    let instance = Paged { page: Some(2) };
+    assert_eq!(
+        instance.to_url("https://api.example.com/items"),
+        "https://api.example.com/items?page=2"
+    );
+}
+
+#[test]
+fn test_to_url_with_existing_query() {
+    let instance = Paged { page: Some(2) };
+    assert_eq!(
+        instance.to_url("https://api.example.com/items?sort=asc"),
+        "https://api.example.com/items?sort=asc&page=2"
+    );
+}